@@ -0,0 +1,75 @@
+//! Resolving the AoC `session` cookie from somewhere other than a literal string in source.
+
+use std::{env, fs, path::PathBuf};
+
+use crate::error::Error;
+
+const ENV_VARS: [&str; 2] = ["AOC_TOKEN", "AOC_SESSION"];
+const SESSION_FILE_NAMES: [&str; 2] = [".aoc_session", "target/.aoc_session"];
+
+/// The AoC `session` cookie, resolved from one of several sources.
+///
+/// Use [`Credentials::explicit`] to provide the token directly (e.g. from `env!("AOC_TOKEN")`), or
+/// [`Credentials::resolve`] to look it up in priority order: an explicit value, the `AOC_TOKEN` or
+/// `AOC_SESSION` environment variable, a `.env` file in `root_dir`, or a `root_dir/.aoc_session` /
+/// `root_dir/target/.aoc_session` file.
+pub struct Credentials(String);
+
+impl Credentials {
+    /// Uses `token` as-is, without consulting any other source.
+    pub fn explicit(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    /// Resolves the session cookie, preferring `explicit` when given.
+    ///
+    /// Fails with [`Error::NoCredentials`] when none of the sources yield a token. Emits
+    /// `cargo::rerun-if-env-changed` / `cargo::rerun-if-changed` for the sources that were
+    /// consulted, so the build re-runs when the token changes.
+    pub fn resolve(explicit: Option<&str>, root_dir: &str) -> Result<Self, Error> {
+        if let Some(token) = explicit {
+            return Ok(Self(token.to_owned()));
+        }
+
+        for var in ENV_VARS {
+            println!("cargo::rerun-if-env-changed={var}");
+            if let Ok(token) = env::var(var) {
+                return Ok(Self(token.trim_end().to_owned()));
+            }
+        }
+
+        let dotenv_file = PathBuf::from(root_dir).join(".env");
+        println!("cargo::rerun-if-changed={}", dotenv_file.to_string_lossy());
+        if let Some(token) = fs::read_to_string(&dotenv_file)
+            .ok()
+            .and_then(|c| dotenv_token(&c))
+        {
+            return Ok(Self(token));
+        }
+
+        for name in SESSION_FILE_NAMES {
+            let file = PathBuf::from(root_dir).join(name);
+            println!("cargo::rerun-if-changed={}", file.to_string_lossy());
+            if let Ok(token) = fs::read_to_string(&file) {
+                return Ok(Self(token.trim_end().to_owned()));
+            }
+        }
+
+        Err(Error::NoCredentials)
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Finds the value of an `AOC_TOKEN=...` / `AOC_SESSION=...` line in a `.env` file's contents,
+/// stripping surrounding quotes.
+fn dotenv_token(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.trim().split_once('=')?;
+        ENV_VARS
+            .contains(&key.trim())
+            .then(|| value.trim().trim_matches('"').to_owned())
+    })
+}
@@ -18,6 +18,10 @@ pub enum Error {
     IO(String, io::Error),
     Request(String, ureq::Error),
     Date(i8, Box<jiff::Zoned>),
+    NoExample(i8),
+    NoCredentials,
+    Cooldown(std::time::Duration),
+    UnrecognizedResponse(String),
 }
 
 impl Error {
@@ -26,6 +30,10 @@ impl Error {
             Self::IO(_, _) => true,
             Self::Request(_, _) => true,
             Self::Date(_, _) => false,
+            Self::NoExample(_) => false,
+            Self::NoCredentials => true,
+            Self::Cooldown(_) => false,
+            Self::UnrecognizedResponse(_) => true,
         }
     }
 }
@@ -63,6 +71,22 @@ impl Display for Error {
                     formatter.format(&icu_zdt)
                 )
             }
+            Self::NoExample(day) => {
+                write!(f, "no suitable example input block found for day {day}")
+            }
+            Self::NoCredentials => write!(
+                f,
+                "no AoC session token found; pass one explicitly, set AOC_TOKEN/AOC_SESSION, \
+                 or add a .env or .aoc_session file to the project root"
+            ),
+            Self::Cooldown(wait) => write!(
+                f,
+                "submission rate limited, {}s left to wait",
+                wait.as_secs()
+            ),
+            Self::UnrecognizedResponse(message) => {
+                write!(f, "could not classify AoC's response: '{message}'")
+            }
         }
     }
 }
@@ -73,6 +97,10 @@ impl std::error::Error for Error {
             Self::IO(_, error) => Some(error),
             Self::Request(_, error) => Some(error),
             Self::Date(_, _) => None,
+            Self::NoExample(_) => None,
+            Self::NoCredentials => None,
+            Self::Cooldown(_) => None,
+            Self::UnrecognizedResponse(_) => None,
         }
     }
 }
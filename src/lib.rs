@@ -1,18 +1,19 @@
 //! AoC input build is helper library to download input files for [Advent of Code](https://adventofcode.com).
 //!
-//! Provides single function [`download_inputs`]. This function needs to be called from [build.rs](https://doc.rust-lang.org/cargo/reference/build-scripts.html) build script.
-//! It will download all necessary input files for given Advent of Code year.
+//! Provides [`download_inputs`] and [`download_puzzles`]. These functions need to be called from [build.rs](https://doc.rust-lang.org/cargo/reference/build-scripts.html) build script.
+//! [`download_inputs`] downloads all necessary input files for given Advent of Code year, [`download_puzzles`] saves the rendered puzzle statements as Markdown.
 //!
 //!
 //! ```no_run
 //!# #![allow(clippy::needless_doctest_main)]
-//! use aoc_input_build::download_inputs;
+//! use aoc_input_build::{Credentials, ExampleMode, download_inputs};
 //!
 //! fn main() {
 //!     let root_dir = env!("CARGO_MANIFEST_DIR"); // root of the project, should always be set to CARGO_MANIFEST_DIR env var
-//!     let token = env!("AOC_TOKEN"); // session cookie from https://adventofcode.com/
+//!     // session cookie from https://adventofcode.com/, resolved from AOC_TOKEN/AOC_SESSION, a .env file, or a session file
+//!     let credentials = Credentials::resolve(None, root_dir).expect("no AoC session token found");
 //!     let year = 2025; // which year of Advent of Code to use
-//!     download_inputs(root_dir, token, year);
+//!     download_inputs(root_dir, &credentials, year, ExampleMode::Off);
 //! }
 //! ```
 //!
@@ -24,11 +25,24 @@ use std::{collections::HashSet, fs, path::PathBuf, sync::LazyLock};
 use jiff::{Zoned, civil};
 use regex::Regex;
 
-use crate::error::{Error, cargo_error};
+use crate::{
+    error::{Error, cargo_error},
+    throttle::Throttle,
+};
 
+mod credentials;
 mod error;
+mod puzzle;
+mod scaffold;
+mod submit;
+mod throttle;
 
-fn list_days(root_dir: &str) -> Result<impl Iterator<Item = String>, Error> {
+pub use credentials::Credentials;
+pub use puzzle::{ExampleMode, download_puzzles};
+pub use scaffold::scaffold_days;
+pub use submit::{Outcome, Part, submit_answer};
+
+pub(crate) fn list_days(root_dir: &str) -> Result<impl Iterator<Item = String>, Error> {
     let mut src_dir = PathBuf::from(root_dir);
     src_dir.push("src");
 
@@ -43,15 +57,16 @@ fn list_days(root_dir: &str) -> Result<impl Iterator<Item = String>, Error> {
         .filter(|name| DAY_REGEX.is_match(name)))
 }
 
-fn fetch_input(today: &Zoned, session_cookie: &str, year: i16, day: i8) -> Result<String, Error> {
-    const AOC_URL: &str = "https://adventofcode.com";
-    const AOC_USER_AGENT: &str =
-        "https://github.com/olilag/aoc-input-build by oliver.oli.lago@gmail.com";
+pub(crate) const AOC_URL: &str = "https://adventofcode.com";
+pub(crate) const AOC_USER_AGENT: &str =
+    "https://github.com/olilag/aoc-input-build by oliver.oli.lago@gmail.com";
 
-    const AOC_RELEASE_MONTH: i8 = 12;
-    const AOC_RELEASE_HOUR: i8 = 0;
-    const AOC_RELEASE_TZ: &str = "America/New_York";
+const AOC_RELEASE_MONTH: i8 = 12;
+const AOC_RELEASE_HOUR: i8 = 0;
+const AOC_RELEASE_TZ: &str = "America/New_York";
 
+/// Fails with [`Error::Date`] when `today` is before the given day's puzzle has unlocked.
+pub(crate) fn check_release(today: &Zoned, year: i16, day: i8) -> Result<Zoned, Error> {
     let puzzle_release = civil::datetime(year, AOC_RELEASE_MONTH, day, AOC_RELEASE_HOUR, 0, 0, 0)
         .in_tz(AOC_RELEASE_TZ)
         .expect("Failed to create puzzle release datetime");
@@ -60,18 +75,23 @@ fn fetch_input(today: &Zoned, session_cookie: &str, year: i16, day: i8) -> Resul
         return Err(Error::Date(day, Box::new(puzzle_release)));
     }
 
-    let url = format!("{AOC_URL}/{year}/day/{day}/input");
+    Ok(puzzle_release)
+}
+
+fn fetch_input(
+    today: &Zoned,
+    throttle: &mut Throttle,
+    session_cookie: &str,
+    year: i16,
+    day: i8,
+) -> Result<String, Error> {
+    check_release(today, year, day)?;
 
-    let mut resp = ureq::get(&url)
-        .header("User-Agent", AOC_USER_AGENT)
-        .header("Cookie", session_cookie)
-        .call()
-        .map_err(|e| Error::Request(url.clone(), e))?
-        .into_body();
-    resp.read_to_string().map_err(|e| Error::Request(url, e))
+    let url = format!("{AOC_URL}/{year}/day/{day}/input");
+    throttle.get(&url, AOC_USER_AGENT, session_cookie)
 }
 
-fn validate_year(today: &Zoned, year: i16) -> bool {
+pub(crate) fn validate_year(today: &Zoned, year: i16) -> bool {
     // NOTE: this assumes that AoC will be available each year
     if !(2015..=today.year()).contains(&year) {
         println!(
@@ -84,7 +104,7 @@ fn validate_year(today: &Zoned, year: i16) -> bool {
     }
 }
 
-fn validate_day(year: i16, day: i8) -> bool {
+pub(crate) fn validate_day(year: i16, day: i8) -> bool {
     match year {
         // starting from 2025 there will only be 12 days - https://adventofcode.com/2025/about#faq_num_days
         2025.. if !(1..=12).contains(&day) => {
@@ -109,15 +129,24 @@ fn validate_day(year: i16, day: i8) -> bool {
 ///
 /// Downloaded input files will be placed to `root_dir/input` and called `dayXX.txt` where `XX` is day's number.
 ///
-/// `token` is AoC's cookie called `session`. You can find it in your browser.
+/// `credentials` provides AoC's cookie called `session`, see [`Credentials`] for how it can be obtained.
 ///
 /// When `year` is smaller than 2015 or greater than current year, build script will report an error as AoC for that year doesn't exist.
 ///
 /// To download a day, there needs to exist file `root_dir/dayXX.rs` where `XX` is day's number.
 /// If the input file is not yet released or the file for the day does not exist, it will issue a warning and continue.
 ///
+/// `examples` controls whether an example input is also extracted from the puzzle statement and saved
+/// to `root_dir/input/dayXX_example.txt`; see [`ExampleMode`] for the available strategies. Leaving it
+/// at [`ExampleMode::Off`] incurs no extra request.
+///
 /// It will also report any IO or network errors that occurred while fetching and saving input files.
-pub fn download_inputs(root_dir: &str, token: &str, year: i16) -> Option<()> {
+pub fn download_inputs(
+    root_dir: &str,
+    credentials: &Credentials,
+    year: i16,
+    examples: ExampleMode,
+) -> Option<()> {
     const DOWNLOAD_DIR_NAME: &str = "input";
 
     println!("cargo::rerun-if-changed=src");
@@ -143,25 +172,37 @@ pub fn download_inputs(root_dir: &str, token: &str, year: i16) -> Option<()> {
     let res = download_dir
         .read_dir()
         .map_err(|e| Error::IO(download_dir.to_string_lossy().to_string(), e));
+    // Only `.txt` stems count as cached inputs; `dayXX.json` answer caches (see `submit`) also
+    // live in this directory and share a stem with their day's input file.
     let cached: HashSet<String> = cargo_error(res)?
         .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "txt"))
         .flat_map(|e| e.path().file_stem().map(|x| x.to_os_string()))
         .flat_map(|name| name.into_string())
         .collect();
 
-    let formatted_token = format!("session={token}");
+    let formatted_token = format!("session={}", credentials.as_str());
+    let mut throttle = Throttle::new();
 
     for day in days {
-        if !cached.contains(&day) {
-            let n = day[3..]
-                .parse::<i8>()
-                .expect("Failed to convert day string to number");
+        let example_file = download_dir.join(format!("{day}_example.txt"));
+        let need_input = !cached.contains(&day);
+        let need_example = examples != ExampleMode::Off && !example_file.exists();
 
-            if !validate_day(year, n) {
-                continue;
-            }
+        if !need_input && !need_example {
+            continue;
+        }
 
-            let res = fetch_input(&today, &formatted_token, year, n);
+        let n = day[3..]
+            .parse::<i8>()
+            .expect("Failed to convert day string to number");
+
+        if !validate_day(year, n) {
+            continue;
+        }
+
+        if need_input {
+            let res = fetch_input(&today, &mut throttle, &formatted_token, year, n);
             if let Some(inp) = cargo_error(res) {
                 let file = download_dir.join(format!("{day}.txt"));
                 let res = fs::write(&file, inp)
@@ -169,6 +210,18 @@ pub fn download_inputs(root_dir: &str, token: &str, year: i16) -> Option<()> {
                 let _ = cargo_error(res);
             }
         }
+
+        if need_example {
+            let res = puzzle::fetch_puzzle(&today, &mut throttle, &formatted_token, year, n)
+                .and_then(|html| {
+                    puzzle::extract_example(&html, examples).ok_or(Error::NoExample(n))
+                });
+            if let Some(example) = cargo_error(res) {
+                let res = fs::write(&example_file, example)
+                    .map_err(|e| Error::IO(example_file.to_string_lossy().to_string(), e));
+                let _ = cargo_error(res);
+            }
+        }
     }
 
     Some(())
@@ -0,0 +1,213 @@
+//! Downloading and rendering of the puzzle statement itself, as opposed to its input file.
+
+use std::{collections::HashSet, fs, path::PathBuf, sync::LazyLock};
+
+use jiff::Zoned;
+use regex::Regex;
+
+use crate::{
+    AOC_URL, AOC_USER_AGENT, Credentials, check_release,
+    error::{Error, cargo_error},
+    list_days,
+    throttle::Throttle,
+    validate_day, validate_year,
+};
+
+pub(crate) fn fetch_puzzle(
+    today: &Zoned,
+    throttle: &mut Throttle,
+    session_cookie: &str,
+    year: i16,
+    day: i8,
+) -> Result<String, Error> {
+    check_release(today, year, day)?;
+
+    let url = format!("{AOC_URL}/{year}/day/{day}");
+    throttle.get(&url, AOC_USER_AGENT, session_cookie)
+}
+
+/// Returns the contents of every `<article class="day-desc">` block found in `html`, in order.
+///
+/// There is one such article for part 1 and, once it is unlocked, a second one for part 2.
+fn day_desc_articles(html: &str) -> Vec<&str> {
+    static ARTICLE_REGEX: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap());
+
+    ARTICLE_REGEX
+        .captures_iter(html)
+        .map(|c| c.get(1).unwrap().as_str())
+        .collect()
+}
+
+/// Controls whether and how [`crate::download_inputs`] extracts an example input from the puzzle
+/// statement alongside the real one.
+///
+/// Extraction requires fetching the puzzle page in addition to the input, so it is opt-in: users
+/// who leave it at [`ExampleMode::Off`] pay no extra request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExampleMode {
+    /// Do not extract an example input.
+    #[default]
+    Off,
+    /// Use the first `<pre><code>` block in the puzzle statement as the example.
+    First,
+    /// Use the longest multi-line `<pre><code>` block in the puzzle statement as the example.
+    ///
+    /// Some days show a formatted code block before the actual example input, in which case
+    /// [`ExampleMode::First`] picks the wrong one; this heuristic usually does better.
+    Longest,
+}
+
+fn code_blocks(html: &str) -> Vec<&str> {
+    static PRE_CODE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+
+    day_desc_articles(html)
+        .iter()
+        .flat_map(|article| PRE_CODE.captures_iter(article))
+        .map(|c| c.get(1).unwrap().as_str())
+        .collect()
+}
+
+/// Extracts an example input from a puzzle page's HTML according to `mode`.
+///
+/// Returns `None` when `mode` is [`ExampleMode::Off`] or no suitable code block was found.
+pub(crate) fn extract_example(html: &str, mode: ExampleMode) -> Option<String> {
+    let blocks = code_blocks(html);
+
+    let block = match mode {
+        ExampleMode::Off => return None,
+        ExampleMode::First => blocks.into_iter().next(),
+        ExampleMode::Longest => blocks
+            .into_iter()
+            .filter(|b| b.contains('\n'))
+            .max_by_key(|b| b.len()),
+    };
+
+    block.map(|b| unescape_html(&strip_tags(b)))
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Strips inline tags (e.g. the `<em>` AoC wraps highlighted characters in) from a text fragment.
+fn strip_tags(text: &str) -> String {
+    static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]+>").unwrap());
+
+    TAG.replace_all(text, "").into_owned()
+}
+
+/// Converts a single `day-desc` article's inner HTML to Markdown.
+///
+/// AoC only ever uses a small subset of HTML in puzzle statements (headings, paragraphs,
+/// emphasis, links, lists and code blocks), so a handful of regex substitutions are enough
+/// to get a faithful rendering without pulling in a full HTML parser.
+fn article_to_markdown(article: &str) -> String {
+    static PRE_CODE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+    static HEADING: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<h2[^>]*>(.*?)</h2>").unwrap());
+    static LINK: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap());
+    static EMPHASIS: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<em[^>]*>(.*?)</em>").unwrap());
+    static CODE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<code>(.*?)</code>").unwrap());
+    static LIST_ITEM: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<li>(.*?)</li>").unwrap());
+    static PARAGRAPH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<p>(.*?)</p>").unwrap());
+
+    let md = PRE_CODE.replace_all(article, "\n```\n$1\n```\n");
+    let md = HEADING.replace_all(&md, "## $1\n");
+    let md = LINK.replace_all(&md, "[$2]($1)");
+    let md = EMPHASIS.replace_all(&md, "*$1*");
+    let md = CODE.replace_all(&md, "`$1`");
+    let md = LIST_ITEM.replace_all(&md, "- $1\n");
+    let md = PARAGRAPH.replace_all(&md, "$1\n\n");
+    let md = strip_tags(&md);
+
+    unescape_html(md.trim())
+}
+
+/// Renders every `day-desc` article found in `html` to Markdown, separated by a horizontal rule.
+pub(crate) fn html_to_markdown(html: &str) -> String {
+    day_desc_articles(html)
+        .iter()
+        .map(|a| article_to_markdown(a))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Downloads puzzle descriptions for `year`'s Advent of Code. Should be called from `build.rs` build script.
+///
+/// `root_dir` should be set to `env!("CARGO_MANIFEST_DIR")`, this directory is used as parent for `puzzle/` folder and for reading `src/`.
+///
+/// Downloaded puzzle descriptions will be placed to `root_dir/puzzle` and called `dayXX.md` where `XX` is day's number,
+/// mirroring how [`download_inputs`](crate::download_inputs) lays out `root_dir/input`.
+///
+/// `credentials` provides AoC's cookie called `session`, see [`Credentials`] for how it can be obtained.
+///
+/// To download a day, there needs to exist file `root_dir/src/dayXX.rs` where `XX` is day's number;
+/// days without such a file are skipped. If the puzzle is not yet released, it will issue a warning and continue.
+/// If the Markdown file already exists, it is not re-downloaded.
+pub fn download_puzzles(root_dir: &str, credentials: &Credentials, year: i16) -> Option<()> {
+    const PUZZLE_DIR_NAME: &str = "puzzle";
+
+    println!("cargo::rerun-if-changed=src");
+    println!("cargo::rerun-if-changed=puzzle"); // ensure re-run when a puzzle file was deleted
+
+    let today = Zoned::now();
+    if !validate_year(&today, year) {
+        return None;
+    }
+
+    let res = list_days(root_dir);
+    let days = cargo_error(res)?;
+
+    let mut puzzle_dir = PathBuf::from(root_dir);
+    puzzle_dir.push(PUZZLE_DIR_NAME);
+
+    if !puzzle_dir.exists() {
+        let res = fs::create_dir(&puzzle_dir)
+            .map_err(|e| Error::IO(puzzle_dir.to_string_lossy().to_string(), e));
+        cargo_error(res)?;
+    }
+
+    let res = puzzle_dir
+        .read_dir()
+        .map_err(|e| Error::IO(puzzle_dir.to_string_lossy().to_string(), e));
+    let cached: HashSet<String> = cargo_error(res)?
+        .flatten()
+        .flat_map(|e| e.path().file_stem().map(|x| x.to_os_string()))
+        .flat_map(|name| name.into_string())
+        .collect();
+
+    let formatted_token = format!("session={}", credentials.as_str());
+    let mut throttle = Throttle::new();
+
+    for day in days {
+        if !cached.contains(&day) {
+            let n = day[3..]
+                .parse::<i8>()
+                .expect("Failed to convert day string to number");
+
+            if !validate_day(year, n) {
+                continue;
+            }
+
+            let res = fetch_puzzle(&today, &mut throttle, &formatted_token, year, n);
+            if let Some(html) = cargo_error(res) {
+                let file = puzzle_dir.join(format!("{day}.md"));
+                let res = fs::write(&file, html_to_markdown(&html))
+                    .map_err(|e| Error::IO(file.to_string_lossy().to_string(), e));
+                let _ = cargo_error(res);
+            }
+        }
+    }
+
+    Some(())
+}
@@ -0,0 +1,45 @@
+//! Scaffolding missing `dayXX.rs` solution files from a template.
+
+use std::{fs, ops::RangeInclusive, path::PathBuf};
+
+use crate::error::{Error, cargo_error};
+
+/// Creates `src/dayXX.rs` from `template` for every day in `days` that doesn't already have a
+/// source file, mirroring the `cargo scaffold <day>` workflow common in AoC Rust templates.
+///
+/// Since [`download_inputs`](crate::download_inputs) only downloads inputs for days that already
+/// have a source file, scaffolding the file first is what lets a day's input ever get downloaded.
+///
+/// `template` supports `{day}`, `{day_padded}` and `{year}` placeholders, substituted with the day
+/// number, its two-digit zero-padded form, and `year` respectively. Existing files are never
+/// overwritten; a `cargo::warning` is printed for each file that gets created, so a newly added
+/// module doesn't go unnoticed.
+pub fn scaffold_days(
+    root_dir: &str,
+    year: i16,
+    days: RangeInclusive<i8>,
+    template: &str,
+) -> Option<()> {
+    let mut src_dir = PathBuf::from(root_dir);
+    src_dir.push("src");
+
+    for day in days {
+        let file = src_dir.join(format!("day{day:02}.rs"));
+        if file.exists() {
+            continue;
+        }
+
+        let contents = template
+            .replace("{day_padded}", &format!("{day:02}"))
+            .replace("{day}", &day.to_string())
+            .replace("{year}", &year.to_string());
+
+        let res = fs::write(&file, contents)
+            .map_err(|e| Error::IO(file.to_string_lossy().to_string(), e));
+        if cargo_error(res).is_some() {
+            println!("cargo::warning=Created '{}'", file.to_string_lossy());
+        }
+    }
+
+    Some(())
+}
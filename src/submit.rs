@@ -0,0 +1,155 @@
+//! Submitting puzzle answers to AoC and caching known-correct ones so they aren't resubmitted.
+
+use std::{fs, path::PathBuf, sync::LazyLock, time::Duration};
+
+use regex::Regex;
+
+use crate::{AOC_URL, AOC_USER_AGENT, Credentials, error::Error};
+
+/// Which part of a day's puzzle an answer is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    fn level(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The outcome of submitting an answer to AoC.
+///
+/// A rate-limited submission is not represented here; it fails with [`Error::Cooldown`] instead,
+/// since it didn't actually get graded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer was correct.
+    Correct,
+    /// The answer was incorrect.
+    Incorrect,
+    /// This part was already solved, so AoC didn't grade the submission.
+    AlreadyCompleted,
+}
+
+fn cache_file(root_dir: &str, day: i8) -> PathBuf {
+    let mut file = PathBuf::from(root_dir);
+    file.push("input");
+    file.push(format!("day{day:02}.json"));
+    file
+}
+
+/// Reads the levels already known to be correct for `day` from its `dayXX.json` cache.
+fn cached_correct_levels(root_dir: &str, day: i8) -> Vec<u8> {
+    static LEVEL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+").unwrap());
+
+    fs::read_to_string(cache_file(root_dir, day))
+        .ok()
+        .map(|contents| {
+            LEVEL_REGEX
+                .find_iter(&contents)
+                .flat_map(|m| m.as_str().parse())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn cache_correct_level(root_dir: &str, day: i8, part: Part) -> Result<(), Error> {
+    let mut levels = cached_correct_levels(root_dir, day);
+    if !levels.contains(&part.level()) {
+        levels.push(part.level());
+    }
+    levels.sort_unstable();
+
+    let contents = format!(
+        "{{\"correct_levels\":[{}]}}",
+        levels
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let file = cache_file(root_dir, day);
+    fs::write(&file, contents).map_err(|e| Error::IO(file.to_string_lossy().to_string(), e))
+}
+
+/// Parses the `You have X left to wait` cooldown message AoC shows when submitting too soon.
+fn parse_cooldown(message: &str) -> Option<Duration> {
+    static COOLDOWN_REGEX: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?:(\d+)m\s*)?(\d+)s left to wait").unwrap());
+
+    let captures = COOLDOWN_REGEX.captures(message)?;
+    let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = captures[2].parse().ok()?;
+
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+fn classify(message: &str) -> Result<Outcome, Error> {
+    if let Some(wait) = parse_cooldown(message) {
+        return Err(Error::Cooldown(wait));
+    }
+    if message.contains("That's the right answer") {
+        return Ok(Outcome::Correct);
+    }
+    if message.contains("not the right answer") {
+        return Ok(Outcome::Incorrect);
+    }
+    if message.contains("already complete it") {
+        return Ok(Outcome::AlreadyCompleted);
+    }
+
+    Err(Error::UnrecognizedResponse(message.to_string()))
+}
+
+/// Submits `answer` for `year`'s day `day`, `part` to AoC.
+///
+/// `credentials` provides AoC's cookie called `session`, see [`Credentials`] for how it can be obtained.
+///
+/// Already-known-correct answers are cached in `root_dir/input/dayXX.json`; submitting a level
+/// found there short-circuits to [`Outcome::AlreadyCompleted`] without making a request.
+///
+/// Fails with [`Error::Cooldown`] carrying the remaining wait time when AoC's per-submission
+/// rate limit is still in effect, rather than aborting outright; callers should back off and
+/// retry after the given duration. IO and network failures reuse [`Error::IO`] and
+/// [`Error::Request`] as elsewhere in this crate. A response that doesn't match any known
+/// phrasing (a reworded page, an interstitial, a logged-out response, ...) fails with
+/// [`Error::UnrecognizedResponse`] rather than being guessed at.
+pub fn submit_answer(
+    root_dir: &str,
+    credentials: &Credentials,
+    year: i16,
+    day: i8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, Error> {
+    if cached_correct_levels(root_dir, day).contains(&part.level()) {
+        return Ok(Outcome::AlreadyCompleted);
+    }
+
+    let url = format!("{AOC_URL}/{year}/day/{day}/answer");
+    let session_cookie = format!("session={}", credentials.as_str());
+
+    let mut resp = ureq::post(&url)
+        .header("User-Agent", AOC_USER_AGENT)
+        .header("Cookie", &session_cookie)
+        .send_form([
+            ("level", part.level().to_string()),
+            ("answer", answer.to_string()),
+        ])
+        .map_err(|e| Error::Request(url.clone(), e))?
+        .into_body();
+    let message = resp.read_to_string().map_err(|e| Error::Request(url, e))?;
+
+    let outcome = classify(&message)?;
+    if outcome == Outcome::Correct {
+        cache_correct_level(root_dir, day, part)?;
+    }
+
+    Ok(outcome)
+}
@@ -0,0 +1,103 @@
+//! Pacing and retrying outgoing requests so the download loop stays polite to AoC's servers.
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::error::Error;
+
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_ATTEMPTS: u32 = 4;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Enforces a minimum interval between requests and retries failed ones with exponential backoff.
+///
+/// AoC asks tooling not to hammer its endpoints, so every [`Throttle::get`] call waits out
+/// [`MIN_REQUEST_INTERVAL`] since the previous one before firing, and on failure backs off
+/// (1s, 2s, 4s, ...) across up to [`MAX_ATTEMPTS`] attempts. Transient 5xx responses are common
+/// in December, so the full 5xx range is retried; a 429 or 503 additionally honors any
+/// `Retry-After` header instead of the computed backoff.
+pub(crate) struct Throttle {
+    last_request: Option<Instant>,
+}
+
+impl Throttle {
+    pub(crate) fn new() -> Self {
+        Self { last_request: None }
+    }
+
+    fn wait_out_interval(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < MIN_REQUEST_INTERVAL {
+                thread::sleep(MIN_REQUEST_INTERVAL - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// GETs `url` with the given `User-Agent` and session cookie, applying the throttle and retry policy.
+    pub(crate) fn get(
+        &mut self,
+        url: &str,
+        user_agent: &str,
+        session_cookie: &str,
+    ) -> Result<String, Error> {
+        let mut backoff = INITIAL_BACKOFF;
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            self.wait_out_interval();
+
+            let resp = ureq::get(url)
+                .header("User-Agent", user_agent)
+                .header("Cookie", session_cookie)
+                .config()
+                .http_status_as_error(false)
+                .build()
+                .call()
+                .map_err(|e| Error::Request(url.to_string(), e));
+
+            match resp {
+                Ok(mut resp) if resp.status().is_success() => {
+                    return resp
+                        .body_mut()
+                        .read_to_string()
+                        .map_err(|e| Error::Request(url.to_string(), e));
+                }
+                Ok(resp) => {
+                    let status = resp.status().as_u16();
+                    let last_attempt = attempt == MAX_ATTEMPTS;
+                    if last_attempt || !matches!(status, 429 | 500..=599) {
+                        return Err(Error::Request(
+                            url.to_string(),
+                            ureq::Error::StatusCode(status),
+                        ));
+                    }
+                    let retry_after = matches!(status, 429 | 503)
+                        .then(|| retry_after(&resp))
+                        .flatten();
+                    thread::sleep(retry_after.unwrap_or(backoff));
+                }
+                Err(e) => {
+                    if attempt == MAX_ATTEMPTS {
+                        return Err(e);
+                    }
+                    thread::sleep(backoff);
+                }
+            }
+
+            backoff *= 2;
+        }
+
+        unreachable!("the loop above always returns by the last attempt")
+    }
+}
+
+fn retry_after(resp: &ureq::http::Response<ureq::Body>) -> Option<Duration> {
+    resp.headers()
+        .get("Retry-After")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}